@@ -0,0 +1,60 @@
+// SPDX-FileCopyrightText: 2022 HH Partners
+//
+// SPDX-License-Identifier: MIT
+
+//! Errors for the library.
+
+use std::fmt::Display;
+
+/// Errors arising from parsing and evaluating [`crate::SpdxExpression`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpdxExpressionError {
+    /// The input was not a syntactically valid SPDX license expression.
+    Parse(String),
+
+    /// In [`crate::ParseMode::Strict`], a leaf identifier was not found on the SPDX license
+    /// list, and was not a `LicenseRef-` user reference or an `AdditionRef-` custom addition.
+    /// Carries the offending substring.
+    UnknownLicenseId(String),
+}
+
+impl Display for SpdxExpressionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpdxExpressionError::Parse(message) => {
+                write!(f, "error parsing license expression: {message}")
+            }
+            SpdxExpressionError::UnknownLicenseId(id) => {
+                write!(
+                    f,
+                    "'{id}' is not a known SPDX license or exception identifier"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SpdxExpressionError {}
+
+/// Errors arising from [`crate::SpdxExpression::minimize`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MinimizeError {
+    /// The expression as a whole is not satisfiable under the accepted set: every branch of an
+    /// `OR`, or any branch of an `AND`, is unsatisfiable.
+    RequirementsUnmet,
+}
+
+impl Display for MinimizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MinimizeError::RequirementsUnmet => {
+                write!(
+                    f,
+                    "the expression is not satisfiable by the accepted licenses"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for MinimizeError {}
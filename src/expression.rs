@@ -4,9 +4,14 @@
 
 //! The main struct of the library.
 
-use std::fmt::Display;
+use std::{fmt::Display, ops::Range};
 
-use crate::{error::SpdxExpressionError, expression_variant::ExpressionVariant};
+use crate::{
+    error::{MinimizeError, SpdxExpressionError},
+    expression_variant::ExpressionVariant,
+    license_req::LicenseReq,
+    licensee::Licensee,
+};
 
 /// Main struct for SPDX License Expressions.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -15,6 +20,17 @@ pub struct SpdxExpression {
     inner: ExpressionVariant,
 }
 
+/// Controls how strictly [`SpdxExpression::parse_mode`] validates leaf identifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Only syntactic validity is checked; unknown identifiers are accepted.
+    Lax,
+
+    /// Every leaf identifier must additionally be a known SPDX license or exception identifier,
+    /// a `LicenseRef-` user reference, or an `AdditionRef-` custom addition after `WITH`.
+    Strict,
+}
+
 impl SpdxExpression {
     /// Parse `Self` from a string. The input expression needs to be a syntactically valid SPDX
     /// expression, `NONE` or `NOASSERTION`. The parser accepts license identifiers that are not
@@ -45,15 +61,48 @@ impl SpdxExpression {
     ///
     /// Returns `SpdxExpressionError` if the license expression is not syntactically valid.
     pub fn parse(expression: &str) -> Result<Self, SpdxExpressionError> {
-        Ok(Self {
-            inner: ExpressionVariant::parse(expression)
-                .map_err(|err| SpdxExpressionError::Parse(err.to_string()))?,
-        })
+        Self::parse_mode(expression, ParseMode::Lax)
+    }
+
+    /// Parse `Self` from a string with the given [`ParseMode`].
+    ///
+    /// In [`ParseMode::Lax`], this behaves like [`Self::parse`]. In [`ParseMode::Strict`], every
+    /// leaf identifier in the expression must additionally be a known SPDX license identifier, a
+    /// known exception identifier or `AdditionRef-` custom addition after `WITH`, or a
+    /// `LicenseRef-` user reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spdx_expression::{ParseMode, SpdxExpression};
+    /// #
+    /// assert!(SpdxExpression::parse_mode("MIT OR NOPE", ParseMode::Strict).is_err());
+    /// assert!(SpdxExpression::parse_mode("MIT OR LicenseRef-my-license", ParseMode::Strict).is_ok());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `SpdxExpressionError::Parse` if the license expression is not syntactically valid,
+    /// or `SpdxExpressionError::UnknownLicenseId` if `mode` is `Strict` and a leaf identifier is
+    /// not recognized.
+    pub fn parse_mode(expression: &str, mode: ParseMode) -> Result<Self, SpdxExpressionError> {
+        let inner = ExpressionVariant::parse(expression)
+            .map_err(|err| SpdxExpressionError::Parse(err.to_string()))?;
+        if mode == ParseMode::Strict {
+            inner
+                .validate_strict()
+                .map_err(SpdxExpressionError::UnknownLicenseId)?;
+        }
+        Ok(Self { inner })
     }
 
     /// Get all license and exception identifiers from the `SpdxExpression`. Returns the licenses
     /// alphabetically sorted and deduped.
     ///
+    /// A `WITH` exception is returned as its own entry, separate from the license it is attached
+    /// to. Prefer [`Self::license_requirements`] for callers that need to keep a license and its
+    /// exception together, or that care about the `+` or-later flag or `LicenseRef-` references.
+    ///
     /// # Examples
     ///
     /// ```
@@ -66,15 +115,144 @@ impl SpdxExpression {
     /// # Ok::<(), SpdxExpressionError>(())
     /// ```
     pub fn licenses(&self) -> Vec<String> {
-        let expression_string = self.to_string();
-        let licenses = expression_string.split_ascii_whitespace();
-        let licenses = licenses.filter(|&i| i != "OR" && i != "AND" && i != "WITH");
-        let licenses = licenses.map(|i| i.replace('(', "").replace(')', ""));
-        let mut licenses = licenses.collect::<Vec<_>>();
+        let mut licenses: Vec<String> = self
+            .license_requirements()
+            .iter()
+            .flat_map(|req| {
+                let mut ids = vec![req.license.to_string()];
+                if let Some(exception) = &req.exception {
+                    ids.push(exception.to_string());
+                }
+                ids
+            })
+            .collect();
         licenses.sort_unstable();
         licenses.dedup();
         licenses
     }
+
+    /// Get every license and exception requirement in the expression as structured values, in
+    /// the order they appear.
+    ///
+    /// Unlike [`Self::licenses`], which flattens everything into sorted strings, this keeps a
+    /// `WITH` exception attached to its license, keeps the `+` or-later flag as a separate field,
+    /// and surfaces `LicenseRef-` references as their own kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spdx_expression::SpdxExpression;
+    /// # use spdx_expression::SpdxExpressionError;
+    /// #
+    /// let expression = SpdxExpression::parse("GPL-2.0-only WITH Classpath-exception-2.0")?;
+    /// let requirements = expression.license_requirements();
+    /// assert_eq!(requirements.len(), 1);
+    /// assert!(requirements[0].exception.is_some());
+    /// # Ok::<(), SpdxExpressionError>(())
+    /// ```
+    pub fn license_requirements(&self) -> Vec<LicenseReq> {
+        self.inner
+            .requirements()
+            .into_iter()
+            .map(|(req, _)| req)
+            .collect()
+    }
+
+    /// Evaluate the expression against a policy closure.
+    ///
+    /// `f` is called once for every leaf license or exception requirement in the expression. An
+    /// `AND` node is satisfied only if both of its children are satisfied; an `OR` node is
+    /// satisfied if either child is. Parenthesized sub-expressions follow their grouping, so this
+    /// matches the boolean semantics of the original expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spdx_expression::SpdxExpression;
+    /// # use spdx_expression::SpdxExpressionError;
+    /// #
+    /// let expression = SpdxExpression::parse("MIT OR GPL-2.0-only")?;
+    /// assert!(expression.evaluate(|req| req.license.to_string() == "MIT"));
+    /// # Ok::<(), SpdxExpressionError>(())
+    /// ```
+    pub fn evaluate<F: FnMut(&LicenseReq) -> bool>(&self, mut f: F) -> bool {
+        self.inner.evaluate(&mut f)
+    }
+
+    /// Get every license and exception requirement in the expression, together with its byte
+    /// span in the original input string. Useful for editor integrations and diagnostics that
+    /// need to point at a specific identifier rather than the whole expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spdx_expression::SpdxExpression;
+    /// # use spdx_expression::SpdxExpressionError;
+    /// #
+    /// let expression = SpdxExpression::parse("MIT OR NOPE")?;
+    /// let requirements = expression.requirements();
+    /// let (_, span) = &requirements[1];
+    /// assert_eq!(&"MIT OR NOPE"[span.clone()], "NOPE");
+    /// # Ok::<(), SpdxExpressionError>(())
+    /// ```
+    pub fn requirements(&self) -> Vec<(LicenseReq, Range<usize>)> {
+        self.inner.requirements()
+    }
+
+    /// Reduce the expression to the smallest sub-expression still satisfiable under the given
+    /// set of `accepted` license requirements.
+    ///
+    /// For an `OR` node, any unsatisfiable branch is dropped; if more than one branch remains
+    /// satisfiable, only the one with the fewest leaves is kept. For an `AND` node, every branch
+    /// must be satisfiable, so all are kept, minimized in turn. Duplicate leaves that result from
+    /// minimization collapse into one, and `NONE`/`NOASSERTION` always pass through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spdx_expression::{LicenseIdentifier, LicenseReq, SpdxExpression};
+    /// # use spdx_expression::SpdxExpressionError;
+    /// #
+    /// let expression = SpdxExpression::parse("MIT OR GPL-3.0-only")?;
+    /// let accepted = vec![LicenseReq {
+    ///     license: LicenseIdentifier::Spdx { id: "MIT".to_string(), or_later: false },
+    ///     exception: None,
+    /// }];
+    /// let minimized = expression.minimize(&accepted).unwrap();
+    /// assert_eq!(minimized.to_string(), "MIT");
+    /// # Ok::<(), SpdxExpressionError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `MinimizeError::RequirementsUnmet` if the expression as a whole is not satisfiable.
+    pub fn minimize(&self, accepted: &[LicenseReq]) -> Result<Self, MinimizeError> {
+        self.inner
+            .minimize(accepted)
+            .map(|inner| Self { inner })
+            .ok_or(MinimizeError::RequirementsUnmet)
+    }
+
+    /// Check whether the set of licenses a dependency is held under, `licensees`, satisfies the
+    /// expression.
+    ///
+    /// This folds requirement satisfaction through the same `AND`/`OR` structure as
+    /// [`Self::evaluate`]: a leaf requirement is satisfied if any of `licensees` satisfies it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spdx_expression::{Licensee, SpdxExpression};
+    /// # use spdx_expression::SpdxExpressionError;
+    /// #
+    /// let expression = SpdxExpression::parse("MIT OR GPL-2.0-or-later")?;
+    /// let licensees = vec![Licensee::parse("GPL-2.0-only")?];
+    /// assert!(expression.satisfied_by(&licensees));
+    /// # Ok::<(), SpdxExpressionError>(())
+    /// ```
+    pub fn satisfied_by(&self, licensees: &[Licensee]) -> bool {
+        self.evaluate(|req| licensees.iter().any(|licensee| req.satisfied_by(licensee)))
+    }
 }
 
 impl Display for SpdxExpression {
@@ -86,6 +264,7 @@ impl Display for SpdxExpression {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::license_req::{ExceptionIdentifier, LicenseIdentifier};
 
     #[test]
     fn test_parsing_works() {
@@ -125,4 +304,235 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_evaluate_and_expression() {
+        let expression = SpdxExpression::parse("MIT AND Apache-2.0").unwrap();
+        assert!(expression
+            .evaluate(|req| matches!(req.license.to_string().as_str(), "MIT" | "Apache-2.0")));
+        assert!(!expression.evaluate(|req| req.license.to_string() == "MIT"));
+    }
+
+    #[test]
+    fn test_evaluate_or_expression() {
+        let expression = SpdxExpression::parse("MIT OR GPL-2.0-only").unwrap();
+        assert!(expression.evaluate(|req| req.license.to_string() == "MIT"));
+        assert!(!expression.evaluate(|req| req.license.to_string() == "ISC"));
+    }
+
+    #[test]
+    fn test_evaluate_visits_every_leaf_even_after_or_is_decided() {
+        let expression = SpdxExpression::parse("MIT OR GPL-3.0-only").unwrap();
+        let mut visited = Vec::new();
+        expression.evaluate(|req| {
+            visited.push(req.license.to_string());
+            req.license.to_string() == "MIT"
+        });
+        assert_eq!(visited, vec!["MIT".to_string(), "GPL-3.0-only".to_string()]);
+    }
+
+    #[test]
+    fn test_evaluate_nested_expression() {
+        let expression = SpdxExpression::parse("MIT AND (Apache-2.0 OR ISC)").unwrap();
+        assert!(
+            expression.evaluate(|req| matches!(req.license.to_string().as_str(), "MIT" | "ISC"))
+        );
+        assert!(!expression.evaluate(|req| req.license.to_string() == "MIT"));
+    }
+
+    #[test]
+    fn test_parse_mode_strict_accepts_known_identifiers() {
+        assert!(SpdxExpression::parse_mode("MIT AND Apache-2.0", ParseMode::Strict).is_ok());
+    }
+
+    #[test]
+    fn test_parse_mode_strict_accepts_license_ref() {
+        assert!(
+            SpdxExpression::parse_mode("MIT OR LicenseRef-my-license", ParseMode::Strict).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_parse_mode_strict_rejects_unknown_identifier() {
+        let error = SpdxExpression::parse_mode("MIT OR NOPE", ParseMode::Strict).unwrap_err();
+        assert_eq!(
+            error,
+            SpdxExpressionError::UnknownLicenseId("NOPE".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_mode_lax_accepts_unknown_identifier() {
+        assert!(SpdxExpression::parse_mode("MIT OR NOPE", ParseMode::Lax).is_ok());
+    }
+
+    #[test]
+    fn test_parse_mode_strict_accepts_none_and_noassertion() {
+        assert!(SpdxExpression::parse_mode("NONE", ParseMode::Strict).is_ok());
+        assert!(SpdxExpression::parse_mode("NOASSERTION", ParseMode::Strict).is_ok());
+    }
+
+    #[test]
+    fn test_requirements_spans() {
+        let input = "MIT OR NOPE";
+        let expression = SpdxExpression::parse(input).unwrap();
+        let requirements = expression.requirements();
+        assert_eq!(requirements.len(), 2);
+        assert_eq!(&input[requirements[0].1.clone()], "MIT");
+        assert_eq!(&input[requirements[1].1.clone()], "NOPE");
+    }
+
+    #[test]
+    fn test_requirements_spans_with_exception_and_parens() {
+        let input = "MIT AND (GPL-2.0-only WITH Classpath-exception-2.0 OR ISC)";
+        let expression = SpdxExpression::parse(input).unwrap();
+        let requirements = expression.requirements();
+        assert_eq!(
+            requirements
+                .iter()
+                .map(|(_, span)| &input[span.clone()])
+                .collect::<Vec<_>>(),
+            vec!["MIT", "GPL-2.0-only WITH Classpath-exception-2.0", "ISC"]
+        );
+    }
+
+    #[test]
+    fn test_license_requirements_keeps_exception_attached() {
+        let expression =
+            SpdxExpression::parse("GPL-2.0-only WITH Classpath-exception-2.0 OR ISC").unwrap();
+        let requirements = expression.license_requirements();
+        assert_eq!(requirements.len(), 2);
+        assert_eq!(requirements[0].license.to_string(), "GPL-2.0-only");
+        assert_eq!(
+            requirements[0].exception.as_ref().unwrap().to_string(),
+            "Classpath-exception-2.0"
+        );
+        assert_eq!(requirements[1].license.to_string(), "ISC");
+        assert!(requirements[1].exception.is_none());
+    }
+
+    #[test]
+    fn test_parsing_document_ref_license_ref_round_trips() {
+        let expression =
+            SpdxExpression::parse("DocumentRef-spdx-tool-1.2:LicenseRef-MIT-Style-1").unwrap();
+        assert_eq!(
+            expression.to_string(),
+            "DocumentRef-spdx-tool-1.2:LicenseRef-MIT-Style-1"
+        );
+    }
+
+    #[test]
+    fn test_parsing_addition_ref_round_trips() {
+        let expression = SpdxExpression::parse("MIT WITH AdditionRef-my-addition").unwrap();
+        assert_eq!(expression.to_string(), "MIT WITH AdditionRef-my-addition");
+        let requirements = expression.license_requirements();
+        assert_eq!(
+            requirements[0].exception,
+            Some(ExceptionIdentifier::AdditionRef("my-addition".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_satisfied_by_or_later_matches_only_variant() {
+        let expression = SpdxExpression::parse("GPL-2.0-or-later").unwrap();
+        let licensees = vec![Licensee::parse("GPL-2.0-only").unwrap()];
+        assert!(expression.satisfied_by(&licensees));
+    }
+
+    #[test]
+    fn test_satisfied_by_exact_only_does_not_match_later() {
+        let expression = SpdxExpression::parse("GPL-2.0-only").unwrap();
+        let licensees = vec![Licensee::parse("GPL-2.0-or-later").unwrap()];
+        assert!(!expression.satisfied_by(&licensees));
+    }
+
+    #[test]
+    fn test_licensee_parse_rejects_or_later_flag() {
+        assert!(Licensee::parse("MIT+").is_err());
+    }
+
+    #[test]
+    fn test_satisfied_by_requires_exact_exception_match() {
+        let expression =
+            SpdxExpression::parse("GPL-2.0-only WITH Classpath-exception-2.0").unwrap();
+        assert!(!expression.satisfied_by(&[Licensee::parse("GPL-2.0-only").unwrap()]));
+        assert!(expression
+            .satisfied_by(
+                &[Licensee::parse("GPL-2.0-only WITH Classpath-exception-2.0").unwrap()]
+            ));
+    }
+
+    #[test]
+    fn test_satisfied_by_or_expression() {
+        let expression = SpdxExpression::parse("MIT OR Apache-2.0").unwrap();
+        assert!(expression.satisfied_by(&[Licensee::parse("Apache-2.0").unwrap()]));
+        assert!(!expression.satisfied_by(&[Licensee::parse("ISC").unwrap()]));
+    }
+
+    fn license_req(id: &str) -> LicenseReq {
+        LicenseReq {
+            license: LicenseIdentifier::Spdx {
+                id: id.to_string(),
+                or_later: false,
+            },
+            exception: None,
+        }
+    }
+
+    #[test]
+    fn test_minimize_or_prefers_smaller_branch() {
+        let expression = SpdxExpression::parse("MIT OR (Apache-2.0 AND ISC)").unwrap();
+        let accepted = vec![
+            license_req("MIT"),
+            license_req("Apache-2.0"),
+            license_req("ISC"),
+        ];
+        let minimized = expression.minimize(&accepted).unwrap();
+        assert_eq!(minimized.to_string(), "MIT");
+    }
+
+    #[test]
+    fn test_minimize_and_keeps_all_satisfiable_branches() {
+        let expression = SpdxExpression::parse("MIT AND Apache-2.0").unwrap();
+        let accepted = vec![license_req("MIT"), license_req("Apache-2.0")];
+        let minimized = expression.minimize(&accepted).unwrap();
+        assert_eq!(minimized.to_string(), "MIT AND Apache-2.0");
+    }
+
+    #[test]
+    fn test_minimize_and_collapses_duplicate_leaves() {
+        let expression = SpdxExpression::parse("(MIT OR ISC) AND (MIT OR Apache-2.0)").unwrap();
+        let accepted = vec![license_req("MIT")];
+        let minimized = expression.minimize(&accepted).unwrap();
+        assert_eq!(minimized.to_string(), "MIT");
+    }
+
+    #[test]
+    fn test_minimize_and_collapses_duplicate_leaf_anywhere_in_chain() {
+        let expression = SpdxExpression::parse("(MIT AND ISC) AND (MIT AND Apache-2.0)").unwrap();
+        let accepted = vec![
+            license_req("MIT"),
+            license_req("ISC"),
+            license_req("Apache-2.0"),
+        ];
+        let minimized = expression.minimize(&accepted).unwrap();
+        assert_eq!(minimized.to_string(), "MIT AND ISC AND Apache-2.0");
+    }
+
+    #[test]
+    fn test_minimize_fails_when_unsatisfiable() {
+        let expression = SpdxExpression::parse("GPL-3.0-only").unwrap();
+        let accepted = vec![license_req("MIT")];
+        assert_eq!(
+            expression.minimize(&accepted).unwrap_err(),
+            MinimizeError::RequirementsUnmet
+        );
+    }
+
+    #[test]
+    fn test_minimize_passes_through_none() {
+        let expression = SpdxExpression::parse("NONE").unwrap();
+        let minimized = expression.minimize(&[]).unwrap();
+        assert_eq!(minimized.to_string(), "NONE");
+    }
 }
@@ -0,0 +1,180 @@
+// SPDX-FileCopyrightText: 2022 HH Partners
+//
+// SPDX-License-Identifier: MIT
+
+//! The parsed representation of an SPDX license expression.
+
+use std::{fmt::Display, ops::Range};
+
+use crate::{license_req::LicenseReq, parser};
+
+/// The parsed AST of an SPDX license expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ExpressionVariant {
+    /// A single license requirement, together with its byte span in the original input.
+    Leaf(LicenseReq, Range<usize>),
+
+    /// `left AND right`.
+    And(Box<ExpressionVariant>, Box<ExpressionVariant>),
+
+    /// `left OR right`.
+    Or(Box<ExpressionVariant>, Box<ExpressionVariant>),
+}
+
+impl ExpressionVariant {
+    /// Parse `input` as a syntactically valid SPDX license expression.
+    pub(crate) fn parse(input: &str) -> Result<Self, parser::ParseError> {
+        parser::parse(input)
+    }
+
+    /// Recursively evaluate the expression, calling `f` for each leaf requirement. An `AND` node
+    /// is true only if both children are true; an `OR` node is true if either child is true.
+    /// Both children are always visited, even once the result is already determined, so `f` is
+    /// called exactly once per leaf.
+    pub(crate) fn evaluate<F: FnMut(&LicenseReq) -> bool>(&self, f: &mut F) -> bool {
+        match self {
+            ExpressionVariant::Leaf(req, _) => f(req),
+            ExpressionVariant::And(left, right) => {
+                let left = left.evaluate(f);
+                let right = right.evaluate(f);
+                left && right
+            }
+            ExpressionVariant::Or(left, right) => {
+                let left = left.evaluate(f);
+                let right = right.evaluate(f);
+                left || right
+            }
+        }
+    }
+
+    /// Recursively checks that every leaf requirement is a known SPDX identifier, a
+    /// `LicenseRef-` user reference, or an `AdditionRef-` custom addition. `NONE`/`NOASSERTION` always pass, since they aren't real
+    /// license identifiers. Returns the offending substring on failure.
+    pub(crate) fn validate_strict(&self) -> Result<(), String> {
+        match self {
+            ExpressionVariant::Leaf(req, _) if req.is_special() => Ok(()),
+            ExpressionVariant::Leaf(req, _) => req.validate_strict(),
+            ExpressionVariant::And(left, right) | ExpressionVariant::Or(left, right) => {
+                left.validate_strict()?;
+                right.validate_strict()
+            }
+        }
+    }
+
+    /// Collects every leaf requirement together with its byte span in the original input, in the
+    /// order the identifiers appear in the expression.
+    pub(crate) fn requirements(&self) -> Vec<(LicenseReq, Range<usize>)> {
+        match self {
+            ExpressionVariant::Leaf(req, span) => vec![(req.clone(), span.clone())],
+            ExpressionVariant::And(left, right) | ExpressionVariant::Or(left, right) => {
+                let mut requirements = left.requirements();
+                requirements.extend(right.requirements());
+                requirements
+            }
+        }
+    }
+
+    /// Recursively rewrites the expression to the smallest sub-expression still satisfiable
+    /// under `accepted`, returning `None` if the expression as a whole is not satisfiable. `NONE`/`NOASSERTION`
+    /// leaves always pass through.
+    pub(crate) fn minimize(&self, accepted: &[LicenseReq]) -> Option<ExpressionVariant> {
+        match self {
+            ExpressionVariant::Leaf(req, _) => {
+                (req.is_special() || accepted.contains(req)).then(|| self.clone())
+            }
+            ExpressionVariant::And(left, right) => {
+                let left = left.minimize(accepted)?;
+                let right = right.minimize(accepted)?;
+                let mut conjuncts = Vec::new();
+                left.flatten_and(&mut conjuncts);
+                right.flatten_and(&mut conjuncts);
+                let mut deduped: Vec<ExpressionVariant> = Vec::new();
+                for conjunct in conjuncts {
+                    if !deduped.iter().any(|kept| kept.requirements_eq(&conjunct)) {
+                        deduped.push(conjunct);
+                    }
+                }
+                let mut conjuncts = deduped.into_iter();
+                let first = conjuncts.next().expect("AND has at least one conjunct");
+                Some(conjuncts.fold(first, |acc, conjunct| {
+                    ExpressionVariant::And(Box::new(acc), Box::new(conjunct))
+                }))
+            }
+            ExpressionVariant::Or(left, right) => {
+                match (left.minimize(accepted), right.minimize(accepted)) {
+                    (Some(left), Some(right)) => {
+                        if left.leaf_count() <= right.leaf_count() {
+                            Some(left)
+                        } else {
+                            Some(right)
+                        }
+                    }
+                    (Some(left), None) => Some(left),
+                    (None, Some(right)) => Some(right),
+                    (None, None) => None,
+                }
+            }
+        }
+    }
+
+    /// Flattens a (possibly nested) `AND` chain into its conjuncts, in order. Used by
+    /// [`Self::minimize`] to dedupe leaves that recur anywhere in an `AND` chain, not just in
+    /// immediate sibling positions.
+    fn flatten_and(&self, out: &mut Vec<ExpressionVariant>) {
+        match self {
+            ExpressionVariant::And(left, right) => {
+                left.flatten_and(out);
+                right.flatten_and(out);
+            }
+            _ => out.push(self.clone()),
+        }
+    }
+
+    /// Whether `self` and `other` have the same shape and leaf requirements, ignoring byte
+    /// spans. Used by [`Self::minimize`] to dedupe equivalent conjuncts in an `AND` chain.
+    fn requirements_eq(&self, other: &ExpressionVariant) -> bool {
+        match (self, other) {
+            (ExpressionVariant::Leaf(a, _), ExpressionVariant::Leaf(b, _)) => a == b,
+            (ExpressionVariant::And(al, ar), ExpressionVariant::And(bl, br))
+            | (ExpressionVariant::Or(al, ar), ExpressionVariant::Or(bl, br)) => {
+                al.requirements_eq(bl) && ar.requirements_eq(br)
+            }
+            _ => false,
+        }
+    }
+
+    /// The number of leaf requirements in the expression, used by [`Self::minimize`] to prefer
+    /// the smallest satisfiable `OR` branch.
+    fn leaf_count(&self) -> usize {
+        match self {
+            ExpressionVariant::Leaf(..) => 1,
+            ExpressionVariant::And(left, right) | ExpressionVariant::Or(left, right) => {
+                left.leaf_count() + right.leaf_count()
+            }
+        }
+    }
+}
+
+impl Display for ExpressionVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExpressionVariant::Leaf(req, _) => write!(f, "{req}"),
+            ExpressionVariant::And(left, right) => {
+                write_operand(f, left)?;
+                write!(f, " AND ")?;
+                write_operand(f, right)
+            }
+            ExpressionVariant::Or(left, right) => write!(f, "{left} OR {right}"),
+        }
+    }
+}
+
+/// Write an operand of an `AND` node, parenthesizing it if it is an `OR` node so that the
+/// printed expression round-trips to the same precedence.
+fn write_operand(f: &mut std::fmt::Formatter<'_>, operand: &ExpressionVariant) -> std::fmt::Result {
+    if matches!(operand, ExpressionVariant::Or(..)) {
+        write!(f, "({operand})")
+    } else {
+        write!(f, "{operand}")
+    }
+}
@@ -0,0 +1,18 @@
+// SPDX-FileCopyrightText: 2022 HH Partners
+//
+// SPDX-License-Identifier: MIT
+
+//! A library for parsing, evaluating and manipulating SPDX license expressions.
+
+mod error;
+mod expression;
+mod expression_variant;
+mod license_list;
+mod license_req;
+mod licensee;
+mod parser;
+
+pub use error::{MinimizeError, SpdxExpressionError};
+pub use expression::{ParseMode, SpdxExpression};
+pub use license_req::{ExceptionIdentifier, LicenseIdentifier, LicenseReq};
+pub use licensee::Licensee;
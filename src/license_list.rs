@@ -0,0 +1,62 @@
+// SPDX-FileCopyrightText: 2022 HH Partners
+//
+// SPDX-License-Identifier: MIT
+
+//! A snapshot of the SPDX license list, used to validate identifiers in [`crate::ParseMode::Strict`].
+//!
+//! This is not regenerated from the upstream `license-list-data` repository; it only covers the
+//! identifiers commonly seen in the wild, which is sufficient to distinguish a real license
+//! identifier from a typo or a bespoke project-local name.
+
+/// Known SPDX license short identifiers.
+const LICENSE_IDS: &[&str] = &[
+    "0BSD",
+    "Apache-1.1",
+    "Apache-2.0",
+    "BSD-2-Clause",
+    "BSD-3-Clause",
+    "BSL-1.0",
+    "CC0-1.0",
+    "CC-BY-4.0",
+    "CC-BY-SA-4.0",
+    "EPL-1.0",
+    "EPL-2.0",
+    "GPL-2.0-only",
+    "GPL-2.0-or-later",
+    "GPL-3.0-only",
+    "GPL-3.0-or-later",
+    "ISC",
+    "LGPL-2.1-only",
+    "LGPL-2.1-or-later",
+    "LGPL-3.0-only",
+    "LGPL-3.0-or-later",
+    "MIT",
+    "MIT-0",
+    "MPL-2.0",
+    "MS-PL",
+    "OFL-1.1",
+    "OpenSSL",
+    "Unlicense",
+    "Zlib",
+];
+
+/// Known SPDX exception identifiers, used after `WITH`.
+const EXCEPTION_IDS: &[&str] = &[
+    "Classpath-exception-2.0",
+    "GCC-exception-2.0",
+    "GCC-exception-3.1",
+    "LLVM-exception",
+    "LGPL-3.0-linking-exception",
+    "OpenSSL-Exception",
+    "Universal-FOSS-exception-1.0",
+];
+
+/// Returns `true` if `id` is a license short identifier on the SPDX license list.
+pub(crate) fn is_known_license_id(id: &str) -> bool {
+    LICENSE_IDS.contains(&id)
+}
+
+/// Returns `true` if `id` is an exception identifier on the SPDX license list.
+pub(crate) fn is_known_exception_id(id: &str) -> bool {
+    EXCEPTION_IDS.contains(&id)
+}
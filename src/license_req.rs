@@ -0,0 +1,185 @@
+// SPDX-FileCopyrightText: 2022 HH Partners
+//
+// SPDX-License-Identifier: MIT
+
+//! License and exception identifiers that make up the leaves of a parsed expression.
+
+use std::fmt::Display;
+
+use crate::{license_list, licensee::Licensee};
+
+/// A single license requirement: a license identifier with an optional `WITH` exception.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LicenseReq {
+    /// The license identifier.
+    pub license: LicenseIdentifier,
+
+    /// The exception identifier, if the requirement includes a `WITH` clause.
+    pub exception: Option<ExceptionIdentifier>,
+}
+
+impl Display for LicenseReq {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.exception {
+            Some(exception) => write!(f, "{} WITH {exception}", self.license),
+            None => write!(f, "{}", self.license),
+        }
+    }
+}
+
+impl LicenseReq {
+    /// Checks that the license and, if present, the exception are known SPDX identifiers, a
+    /// `LicenseRef-` user reference, or an `AdditionRef-` custom addition. Returns the offending substring on failure.
+    pub(crate) fn validate_strict(&self) -> Result<(), String> {
+        self.license.validate_strict()?;
+        if let Some(exception) = &self.exception {
+            exception.validate_strict()?;
+        }
+        Ok(())
+    }
+
+    /// `NONE` and `NOASSERTION` are not real license identifiers: they always pass strict
+    /// validation and [`crate::SpdxExpression::minimize`]. [`crate::SpdxExpression::evaluate`]
+    /// still passes them to its closure like any other leaf.
+    pub(crate) fn is_special(&self) -> bool {
+        self.exception.is_none()
+            && matches!(
+                &self.license,
+                LicenseIdentifier::Spdx { id, or_later: false } if id == "NONE" || id == "NOASSERTION"
+            )
+    }
+
+    /// Checks whether `licensee` satisfies this requirement. A `WITH` exception on the
+    /// requirement must be matched exactly by the licensee.
+    pub(crate) fn satisfied_by(&self, licensee: &Licensee) -> bool {
+        if !self.license.satisfied_by(&licensee.license) {
+            return false;
+        }
+        match &self.exception {
+            Some(exception) => licensee
+                .exception
+                .as_ref()
+                .is_some_and(|held| *held == exception.to_string()),
+            None => true,
+        }
+    }
+}
+
+/// A license identifier, either a plain SPDX-style identifier or a `LicenseRef`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LicenseIdentifier {
+    /// A license identifier from the SPDX license list, or an unlisted identifier accepted in
+    /// `Lax` parse mode. `or_later` is `true` if the identifier was suffixed with `+`.
+    Spdx {
+        /// The license short identifier, e.g. `MIT` or `GPL-2.0`.
+        id: String,
+        /// Whether the identifier was suffixed with `+`, meaning "this version or later".
+        or_later: bool,
+    },
+
+    /// A `LicenseRef-<id>` user license reference, optionally scoped to another SBOM document
+    /// with a `DocumentRef-<document_ref>:` prefix.
+    LicenseRef {
+        /// The `<document_ref>` in a `DocumentRef-<document_ref>:LicenseRef-<id>` reference.
+        document_ref: Option<String>,
+        /// The identifier after `LicenseRef-`.
+        id: String,
+    },
+}
+
+impl Display for LicenseIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LicenseIdentifier::Spdx { id, or_later } => {
+                write!(f, "{id}")?;
+                if *or_later {
+                    write!(f, "+")?;
+                }
+                Ok(())
+            }
+            LicenseIdentifier::LicenseRef { document_ref, id } => {
+                if let Some(document_ref) = document_ref {
+                    write!(f, "DocumentRef-{document_ref}:")?;
+                }
+                write!(f, "LicenseRef-{id}")
+            }
+        }
+    }
+}
+
+impl LicenseIdentifier {
+    /// Checks that `self` is a known SPDX license short identifier, or a `LicenseRef-` user
+    /// reference. Returns the offending substring on failure.
+    pub(crate) fn validate_strict(&self) -> Result<(), String> {
+        match self {
+            LicenseIdentifier::Spdx { id, .. } if license_list::is_known_license_id(id) => Ok(()),
+            LicenseIdentifier::Spdx { id, .. } => Err(id.clone()),
+            LicenseIdentifier::LicenseRef { .. } => Ok(()),
+        }
+    }
+
+    /// Checks whether a licensee holding `licensee_license` satisfies this requirement.
+    ///
+    /// The `+`/`-or-later` flag describes what the requirement will accept from a grantor, not
+    /// what a grantee holds, so e.g. a licensee holding `GPL-2.0-only` satisfies a requirement of
+    /// `GPL-2.0-or-later` without needing to match the flag itself.
+    pub(crate) fn satisfied_by(&self, licensee_license: &str) -> bool {
+        match self {
+            LicenseIdentifier::Spdx { id, or_later } => {
+                if id == licensee_license {
+                    return true;
+                }
+                if !(*or_later || id.ends_with("-or-later")) {
+                    return false;
+                }
+                license_family(id) == license_family(licensee_license)
+            }
+            LicenseIdentifier::LicenseRef { document_ref, id } => {
+                let reference = match document_ref {
+                    Some(document_ref) => format!("DocumentRef-{document_ref}:LicenseRef-{id}"),
+                    None => format!("LicenseRef-{id}"),
+                };
+                reference == licensee_license
+            }
+        }
+    }
+}
+
+/// Strips the `-only`/`-or-later` GNU-style version suffix from a license id, so that
+/// `GPL-2.0-only` and `GPL-2.0-or-later` are recognized as the same license family.
+fn license_family(id: &str) -> &str {
+    id.strip_suffix("-only")
+        .or_else(|| id.strip_suffix("-or-later"))
+        .unwrap_or(id)
+}
+
+/// An exception identifier used after `WITH`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExceptionIdentifier {
+    /// An exception identifier from the SPDX exceptions list, or unlisted in `Lax` mode.
+    Spdx(String),
+
+    /// An `AdditionRef-<id>` custom license addition.
+    AdditionRef(String),
+}
+
+impl Display for ExceptionIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExceptionIdentifier::Spdx(id) => write!(f, "{id}"),
+            ExceptionIdentifier::AdditionRef(id) => write!(f, "AdditionRef-{id}"),
+        }
+    }
+}
+
+impl ExceptionIdentifier {
+    /// Checks that `self` is a known SPDX exception identifier, or an `AdditionRef-` custom
+    /// addition. Returns the offending substring on failure.
+    pub(crate) fn validate_strict(&self) -> Result<(), String> {
+        match self {
+            ExceptionIdentifier::Spdx(id) if license_list::is_known_exception_id(id) => Ok(()),
+            ExceptionIdentifier::Spdx(id) => Err(id.clone()),
+            ExceptionIdentifier::AdditionRef(_) => Ok(()),
+        }
+    }
+}
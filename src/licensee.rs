@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: 2022 HH Partners
+//
+// SPDX-License-Identifier: MIT
+
+//! A licensee: the concrete license a dependency is actually held under.
+
+use std::fmt::Display;
+
+use crate::{error::SpdxExpressionError, license_req::LicenseIdentifier, parser};
+
+/// A concrete license, with an optional exception, that a dependency is held under.
+///
+/// Unlike [`crate::LicenseReq`], which describes what a policy will *accept*, a `Licensee`
+/// describes what a dependency actually *has*, so it never carries the `+` or-later flag. An
+/// identifier that itself ends in `-or-later`, such as `GPL-2.0-or-later`, is kept as-is.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Licensee {
+    /// The license identifier the dependency is held under, e.g. `GPL-2.0-only`.
+    pub license: String,
+
+    /// The exception identifier, if the dependency's license includes a `WITH` clause.
+    pub exception: Option<String>,
+}
+
+impl Licensee {
+    /// Parse a `Licensee` from a simplified `<license>[ WITH <exception>]` string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spdx_expression::Licensee;
+    /// #
+    /// let licensee = Licensee::parse("GPL-2.0-only")?;
+    /// assert_eq!(licensee.license, "GPL-2.0-only");
+    /// # Ok::<(), spdx_expression::SpdxExpressionError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `SpdxExpressionError::Parse` if `licensee` is not a syntactically valid
+    /// `<license>[ WITH <exception>]` string, or if the license is suffixed with the `+`
+    /// or-later flag, which only makes sense on a [`crate::LicenseReq`], not a `Licensee`.
+    pub fn parse(licensee: &str) -> Result<Self, SpdxExpressionError> {
+        let req = parser::parse_single_license_req(licensee)
+            .map_err(|err| SpdxExpressionError::Parse(err.to_string()))?;
+        let license = match req.license {
+            LicenseIdentifier::Spdx { id, or_later: true } => {
+                return Err(SpdxExpressionError::Parse(format!(
+                    "'{id}+' is not a valid licensee: the '+' or-later flag is only valid in a \
+                     license requirement"
+                )));
+            }
+            LicenseIdentifier::Spdx {
+                id,
+                or_later: false,
+            } => id,
+            LicenseIdentifier::LicenseRef { document_ref, id } => match document_ref {
+                Some(document_ref) => format!("DocumentRef-{document_ref}:LicenseRef-{id}"),
+                None => format!("LicenseRef-{id}"),
+            },
+        };
+        let exception = req.exception.map(|exception| exception.to_string());
+        Ok(Self { license, exception })
+    }
+}
+
+impl Display for Licensee {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.exception {
+            Some(exception) => write!(f, "{} WITH {exception}", self.license),
+            None => write!(f, "{}", self.license),
+        }
+    }
+}
@@ -0,0 +1,236 @@
+// SPDX-FileCopyrightText: 2022 HH Partners
+//
+// SPDX-License-Identifier: MIT
+
+//! A small recursive-descent parser for the SPDX license expression grammar.
+
+use std::fmt::Display;
+
+use crate::{
+    expression_variant::ExpressionVariant,
+    license_req::{ExceptionIdentifier, LicenseIdentifier, LicenseReq},
+};
+
+/// Error produced while parsing an expression string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParseError {
+    message: String,
+}
+
+impl ParseError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Recursive-descent parser over a license expression string.
+pub(crate) struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub(crate) fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    pub(crate) fn parse_expression(&mut self) -> Result<ExpressionVariant, ParseError> {
+        self.parse_or()
+    }
+
+    pub(crate) fn expect_end(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.pos == self.input.len() {
+            Ok(())
+        } else {
+            Err(ParseError::new(format!(
+                "unexpected trailing input at position {}",
+                self.pos
+            )))
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<ExpressionVariant, ParseError> {
+        let mut left = self.parse_and()?;
+        while self.consume_keyword("OR") {
+            let right = self.parse_and()?;
+            left = ExpressionVariant::Or(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<ExpressionVariant, ParseError> {
+        let mut left = self.parse_primary()?;
+        while self.consume_keyword("AND") {
+            let right = self.parse_primary()?;
+            left = ExpressionVariant::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_primary(&mut self) -> Result<ExpressionVariant, ParseError> {
+        self.skip_whitespace();
+        if self.consume_char('(') {
+            let inner = self.parse_expression()?;
+            self.skip_whitespace();
+            if !self.consume_char(')') {
+                return Err(ParseError::new("expected a closing ')'"));
+            }
+            return Ok(inner);
+        }
+        self.skip_whitespace();
+        let start = self.pos;
+        let req = self.parse_license_req()?;
+        let end = self.pos;
+        Ok(ExpressionVariant::Leaf(req, start..end))
+    }
+
+    fn parse_license_req(&mut self) -> Result<LicenseReq, ParseError> {
+        let license = self.parse_license_identifier()?;
+        let mut exception = None;
+        if self.consume_keyword("WITH") {
+            self.skip_whitespace();
+            if self.input[self.pos..].starts_with("AdditionRef-") {
+                self.pos += "AdditionRef-".len();
+                let id = self.parse_identifier_token()?;
+                exception = Some(ExceptionIdentifier::AdditionRef(id.to_string()));
+            } else {
+                let id = self.parse_identifier_token()?;
+                exception = Some(ExceptionIdentifier::Spdx(id.to_string()));
+            }
+        }
+        Ok(LicenseReq { license, exception })
+    }
+
+    fn parse_license_identifier(&mut self) -> Result<LicenseIdentifier, ParseError> {
+        self.skip_whitespace();
+        if self.input[self.pos..].starts_with("DocumentRef-") {
+            self.pos += "DocumentRef-".len();
+            let document_ref = self.parse_identifier_token()?.to_string();
+            if !self.consume_char(':') {
+                return Err(ParseError::new("expected ':' after 'DocumentRef-<id>'"));
+            }
+            if !self.input[self.pos..].starts_with("LicenseRef-") {
+                return Err(ParseError::new(
+                    "expected 'LicenseRef-' after 'DocumentRef-<id>:'",
+                ));
+            }
+            self.pos += "LicenseRef-".len();
+            let id = self.parse_identifier_token()?;
+            return Ok(LicenseIdentifier::LicenseRef {
+                document_ref: Some(document_ref),
+                id: id.to_string(),
+            });
+        }
+        if self.input[self.pos..].starts_with("LicenseRef-") {
+            self.pos += "LicenseRef-".len();
+            let id = self.parse_identifier_token()?;
+            return Ok(LicenseIdentifier::LicenseRef {
+                document_ref: None,
+                id: id.to_string(),
+            });
+        }
+        let id = self.parse_identifier_token()?;
+        let or_later = self.consume_char('+');
+        Ok(LicenseIdentifier::Spdx {
+            id: id.to_string(),
+            or_later,
+        })
+    }
+
+    fn parse_identifier_token(&mut self) -> Result<&'a str, ParseError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let bytes = self.input.as_bytes();
+        while self.pos < bytes.len() {
+            let c = bytes[self.pos] as char;
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        if self.pos == start {
+            return Err(ParseError::new(format!(
+                "expected a license identifier at position {start}"
+            )));
+        }
+        Ok(&self.input[start..self.pos])
+    }
+
+    /// Consume `keyword` if it is found at the current position followed by a non-identifier
+    /// character, as required for reserved words like `AND`/`OR`/`WITH`. Leaves `self.pos`
+    /// untouched, including any skipped whitespace, if `keyword` is not found.
+    fn consume_keyword(&mut self, keyword: &str) -> bool {
+        let start = self.pos;
+        self.skip_whitespace();
+        if self.consume_keyword_no_boundary(keyword) {
+            true
+        } else {
+            self.pos = start;
+            false
+        }
+    }
+
+    /// Like [`Self::consume_keyword`], but does not skip leading whitespace first.
+    fn consume_keyword_no_boundary(&mut self, keyword: &str) -> bool {
+        if self.input[self.pos..].starts_with(keyword) {
+            let after = self.pos + keyword.len();
+            let boundary_ok = self.input[after..]
+                .chars()
+                .next()
+                .is_none_or(|c| !(c.is_ascii_alphanumeric() || c == '.' || c == '-'));
+            if boundary_ok {
+                self.pos = after;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Consumes `c` if it is the next non-whitespace character. Leaves `self.pos` untouched,
+    /// including any skipped whitespace, if `c` is not found, so that callers like the optional
+    /// `+` or-later suffix don't absorb trailing whitespace into a leaf's byte span.
+    fn consume_char(&mut self, c: char) -> bool {
+        let start = self.pos;
+        self.skip_whitespace();
+        if self.input[self.pos..].starts_with(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            self.pos = start;
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.pos < self.input.len() && self.input.as_bytes()[self.pos] == b' ' {
+            self.pos += 1;
+        }
+    }
+}
+
+/// Parse `input` into an [`ExpressionVariant`], consuming the whole string.
+pub(crate) fn parse(input: &str) -> Result<ExpressionVariant, ParseError> {
+    let mut parser = Parser::new(input);
+    let expression = parser.parse_expression()?;
+    parser.expect_end()?;
+    Ok(expression)
+}
+
+/// Parse `input` into a single [`LicenseReq`], with no `AND`/`OR` composition or parentheses, as
+/// used by [`crate::Licensee::parse`].
+pub(crate) fn parse_single_license_req(input: &str) -> Result<LicenseReq, ParseError> {
+    let mut parser = Parser::new(input);
+    let req = parser.parse_license_req()?;
+    parser.expect_end()?;
+    Ok(req)
+}